@@ -0,0 +1,102 @@
+// Measures how well the solver does over a batch of random secrets,
+// without touching the terminal - each game is simulated against a plain
+// pattern string rather than driving a `HangmanGame`/`GameBoard`, so the
+// games can run in parallel.
+
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use rayon::prelude::*;
+
+use crate::solver;
+
+const MAX_WRONG_GUESSES: u8 = 6;
+
+pub struct BenchReport {
+    pub games: usize,
+    pub wins: usize,
+    pub win_rate: f64,
+    pub average_wrong_guesses: f64,
+    pub wrong_guess_histogram: [usize; 7],
+}
+
+impl BenchReport {
+    pub fn print(&self) {
+        println!("games:                   {} ({} won)", self.games, self.wins);
+        println!("win rate:                {:.1}%", self.win_rate);
+        println!("average wrong guesses:   {:.2}", self.average_wrong_guesses);
+        println!("wrong-guess histogram:");
+        for (wrong, count) in self.wrong_guess_histogram.iter().enumerate() {
+            println!("  {}: {}", wrong, "#".repeat(*count));
+        }
+    }
+}
+
+/// Run the solver against `n` randomly chosen secrets from `dict` and
+/// summarize the results.
+pub fn run(n: usize, dict: &[String]) -> BenchReport {
+    let mut rng = thread_rng();
+    let secrets: Vec<&String> = (0..n)
+        .filter_map(|_| dict.choose(&mut rng))
+        .collect();
+
+    let results: Vec<(bool, u8)> = secrets.par_iter()
+        .map(|secret| simulate_game(secret, dict))
+        .collect();
+
+    summarize(&results)
+}
+
+// play a single game against `secret`, returning whether the solver won
+// and how many wrong guesses it made along the way
+fn simulate_game(secret: &str, dict: &[String]) -> (bool, u8) {
+    let mut pattern: Vec<char> = secret.chars()
+        .map(|c| if c.is_alphabetic() { '_' } else { c })
+        .collect();
+    let mut guessed: Vec<char> = Vec::new();
+    let mut wrong: Vec<char> = Vec::new();
+
+    while (wrong.len() as u8) < MAX_WRONG_GUESSES {
+        let revealed: String = pattern.iter().collect();
+        if revealed == secret {
+            break;
+        }
+
+        let guess = match solver::next_guess(&revealed, &guessed, &wrong, dict) {
+            Some(guess) => guess,
+            None => break,
+        };
+
+        guessed.push(guess);
+        if secret.contains(guess) {
+            for (slot, letter) in pattern.iter_mut().zip(secret.chars()) {
+                if letter == guess {
+                    *slot = letter;
+                }
+            }
+        } else {
+            wrong.push(guess);
+        }
+    }
+
+    let revealed: String = pattern.iter().collect();
+    (revealed == secret, wrong.len() as u8)
+}
+
+fn summarize(results: &[(bool, u8)]) -> BenchReport {
+    let games = results.len();
+    let wins = results.iter().filter(|(won, _)| *won).count();
+    let total_wrong: usize = results.iter().map(|(_, wrong)| *wrong as usize).sum();
+
+    let mut wrong_guess_histogram = [0usize; 7];
+    for (_, wrong) in results {
+        wrong_guess_histogram[*wrong as usize] += 1;
+    }
+
+    BenchReport {
+        games,
+        wins,
+        win_rate: if games == 0 { 0.0 } else { wins as f64 / games as f64 * 100.0 },
+        average_wrong_guesses: if games == 0 { 0.0 } else { total_wrong as f64 / games as f64 },
+        wrong_guess_histogram,
+    }
+}
@@ -6,9 +6,11 @@ use crossterm::event::{
     KeyEventKind,
     KeyModifiers,
 };
-use std::ops::Range;
+use crossterm::style::Color;
+use std::fs;
+use std::io::{Error, ErrorKind};
 use std::fmt;
-use std::io::Error;
+use std::path::{Path, PathBuf};
 
 mod board;
 use board::{
@@ -16,17 +18,17 @@ use board::{
     Position,
 };
 
-// bit ranges for capital and lowercase alphanumeric characters with *NO*
-// diacritics. Note since `Range` is a half-open interval, the second-to-last
-// element of each range corresponds to Z and z, respectively
-const CAPITAL: Range<u8> = 65..91;
-const LOWERCASE: Range<u8> = 97..123;
+use crate::solver;
 
 // fixed locations in game region
 const PROMPT_LOCATION: Position = Position { row: 2, col: 1 };
 const PHRASE_LOCATION: Position = Position { row: 4, col: 1 };
 const GUESS_LOCATION: Position = Position { row: 8, col: 1 };
 
+// guessing the whole phrase wrong is a bigger gamble than a single letter,
+// so it costs more lives
+const WORD_GUESS_PENALTY: u8 = 2;
+
 
 
 // A custom error type to be thrown when something
@@ -78,25 +80,34 @@ pub struct HangmanGame {
     guesses: Vec<char>,
     should_quit: bool,
     board: GameBoard,
+    save_path: Option<PathBuf>,
 }
 
 impl HangmanGame {
 
-    pub fn new(phrase: &str) -> Self {
+    pub fn new(phrase: &str) -> Result<Self, Error> {
 
-        validate_phrase(phrase);
+        validate_phrase(phrase)?;
         let lives: u8 = 6;
         let guesses: Vec<char> = Vec::new();
         let should_quit = false;
-        let board = GameBoard { padding: phrase.len() };
+        let board = GameBoard { padding: phrase.chars().count() };
 
-        Self {
+        Ok(Self {
             phrase: phrase.to_string(),
             lives,
             guesses,
             should_quit,
             board,
-        }
+            save_path: None,
+        })
+    }
+
+    /// Autosave progress to `path` after every guess, so the game can be
+    /// resumed with [`HangmanGame::load`] after a player quits mid-game.
+    pub fn with_save_path(mut self, path: PathBuf) -> Self {
+        self.save_path = Some(path);
+        self
     }
 
     pub fn play(&mut self) -> Result<(), Error> {
@@ -118,12 +129,51 @@ impl HangmanGame {
 
             let guess = self.read_guess();
             self.evaluate_guess(&guess);
+
+            if let Some(path) = self.save_path.clone() {
+                self.save(&path)?;
+            }
+        }
+
+        self.issue_goodbye()?;
+
+        GameBoard::terminate()?;
+
+        Ok(())
+    }
+
+    /// Play the game automatically, using `solver::next_guess` to pick each
+    /// letter instead of reading keyboard input. `dict` is the pool of
+    /// candidate words the solver filters down as guesses come in.
+    pub fn auto_play(&mut self, dict: &[String]) -> Result<(), Error> {
+
+        GameBoard::initialize()?;
+
+        while !self.should_quit {
+            GameBoard::clear_screen()?;
+            GameBoard::reset_caret()?;
+
+            self.board.print_gallows()?;
+            self.print_body()?;
+            self.print_guess_list()?;
+
+            self.issue_hidden_phrase()?;
+
+            GameBoard::execute()?;
+
+            let pattern = self.construct_hidden_phrase();
+            let wrong = self.wrong_guesses();
+
+            match solver::next_guess(&pattern, &self.guesses, &wrong, dict) {
+                Some(guess) => self.update_guesses(guess),
+                None => self.should_quit = true,
+            }
         }
 
         self.issue_goodbye()?;
 
         GameBoard::terminate()?;
-        
+
         Ok(())
     }
 
@@ -142,10 +192,25 @@ impl HangmanGame {
     }
 
     fn evaluate_guess(&mut self, guess: &str) {
-        if let Some(c) = guess.chars().nth(0) {
-            // if `guess` is an empty string, `nth()` returns `None`
-            // self.add_guess(c);
-            self.update_guesses(c);
+        let mut letters = guess.chars();
+
+        match (letters.next(), letters.next()) {
+            // empty guess; nothing to evaluate
+            (None, _) => {}
+            // single character; fall back to the usual letter guess
+            (Some(c), None) => self.update_guesses(c),
+            // more than one character; treat it as a whole-word guess
+            (Some(_), Some(_)) => self.evaluate_word_guess(guess),
+        }
+    }
+
+    fn evaluate_word_guess(&mut self, guess: &str) {
+        if guess.to_lowercase() == self.phrase.to_lowercase() {
+            self.reveal_all();
+            self.should_quit = true;
+        } else {
+            self.issue_incorrect_word_guess(guess);
+            self.lose_life(WORD_GUESS_PENALTY);
         }
     }
 
@@ -167,14 +232,36 @@ impl HangmanGame {
                 self.issue_correct_guess(&guess);
             } else {
                 self.issue_incorrect_guess(&guess);
+                self.lose_life(1);
+            }
+        }
 
-                self.lives -= 1;
-                if self.lives == 0 {
-                    self.should_quit = true;
+    }
+
+    fn lose_life(&mut self, penalty: u8) {
+        self.lives = self.lives.saturating_sub(penalty);
+        if self.lives == 0 {
+            self.should_quit = true;
+        }
+    }
+
+    // reveal every letter in the secret phrase at once, for a correct
+    // whole-word guess
+    fn reveal_all(&mut self) {
+        for c in self.phrase.chars() {
+            if let Ok(lower) = lowercase(c) {
+                if !self.guesses.contains(&lower) {
+                    self.guesses.push(lower);
                 }
             }
         }
-        
+    }
+
+    fn wrong_guesses(&self) -> Vec<char> {
+        self.guesses.iter()
+            .copied()
+            .filter(|guess| !self.phrase.contains(*guess))
+            .collect()
     }
 
     // endregion
@@ -184,10 +271,8 @@ impl HangmanGame {
     fn construct_hidden_phrase(&self) -> String {
         let mut phrase = String::new();
 
-        for (c, b) in self.phrase.chars().zip(
-            self.phrase.as_bytes().iter()) {
-
-            if is_alpha_utf(b) {
+        for c in self.phrase.chars() {
+            if is_alpha_utf(&c) {
                 if let Ok(lower) = lowercase(c) {
                     if self.guesses.contains(&lower) {
                         phrase.push(c);
@@ -198,7 +283,6 @@ impl HangmanGame {
             } else {
                 phrase.push(c);
             }
-
         }
 
         phrase
@@ -217,7 +301,21 @@ impl HangmanGame {
 
     fn issue_hidden_phrase(&self) -> Result<(), Error> {
         GameBoard::move_caret_to(PHRASE_LOCATION)?;
-        GameBoard::print(&self.construct_hidden_phrase())?;
+
+        // derive each character's color from `construct_hidden_phrase`'s
+        // output rather than re-checking `guesses` here, so the two never
+        // disagree about what's been revealed
+        let hidden = self.construct_hidden_phrase();
+
+        for (secret, revealed) in self.phrase.chars().zip(hidden.chars()) {
+            if revealed == '_' {
+                GameBoard::print("_")?;
+            } else if is_alpha_utf(&secret) {
+                GameBoard::print_styled(&revealed.to_string(), Color::Green)?;
+            } else {
+                GameBoard::print(&revealed.to_string())?;
+            }
+        }
 
         Ok(())
     }
@@ -226,6 +324,10 @@ impl HangmanGame {
         // println!("`{}` is not in the secret phrase :(", guess);
     }
 
+    fn issue_incorrect_word_guess(&self, _guess: &str) {
+        // println!("`{}` is not the secret phrase :(", guess);
+    }
+
     fn issue_invalid_guess(&self, _guess: &char) {
         // println!("invalid guess: `{}`", guess);
     }
@@ -284,56 +386,189 @@ impl HangmanGame {
         let Position { col, row } = GUESS_LOCATION;
         GameBoard::move_caret_to( Position { col: col + 1, row: row + 1 })?;
 
-        let guess_list = self.guesses
-            .iter()
-            .map(|x| x.to_string())
-            .collect::<Vec<_>>()
-            .join(", ");
-        GameBoard::print(&guess_list)?;
+        for (i, guess) in self.guesses.iter().enumerate() {
+            if i > 0 {
+                GameBoard::print(", ")?;
+            }
+
+            let color = if self.phrase.contains(*guess) { Color::Green } else { Color::Red };
+            GameBoard::print_styled(&guess.to_string(), color)?;
+        }
 
         Ok(())
     }
 
     // endregion
 
+    // region: Saving and loading
+
+    /// Write the live game state to `path` as a compact, line-based file:
+    /// phrase, lives, comma-joined guesses, and `should_quit`, one per line.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let guesses = self.guesses.iter()
+            .map(|guess| guess.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let contents = format!(
+            "{}\n{}\n{}\n{}\n",
+            self.phrase,
+            self.lives,
+            guesses,
+            self.should_quit,
+        );
+
+        fs::write(path, contents)
+    }
+
+    /// Restore a game previously written by [`HangmanGame::save`].
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines();
+
+        let invalid = |message: &str| Error::new(ErrorKind::InvalidData, message.to_string());
+
+        let phrase = lines.next()
+            .ok_or_else(|| invalid("save file is missing the phrase line"))?
+            .to_string();
+        validate_phrase(&phrase)?;
+
+        let lives: u8 = lines.next()
+            .ok_or_else(|| invalid("save file is missing the lives line"))?
+            .parse()
+            .map_err(|_| invalid("save file has an invalid lives line"))?;
+
+        let guesses: Vec<char> = match lines.next() {
+            Some(line) if !line.is_empty() => line.split(',')
+                .filter_map(|guess| guess.chars().next())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let should_quit: bool = lines.next()
+            .ok_or_else(|| invalid("save file is missing the should_quit line"))?
+            .parse()
+            .map_err(|_| invalid("save file has an invalid should_quit line"))?;
+
+        let board = GameBoard { padding: phrase.chars().count() };
+
+        Ok(Self {
+            phrase,
+            lives,
+            guesses,
+            should_quit,
+            board,
+            save_path: Some(path.to_path_buf()),
+        })
+    }
+
+    // endregion
+
 }
 
-/// const fn(byte: u8) -> bool
+/// const fn(ch: &char) -> bool
 ///
-/// Check if the given byte corresponds to an alphabetic
-/// character. This function is case-agnostic, and is
-/// equivalent to checking if the byte's character representation
-/// resides in the regex range [a-zA-Z]
-fn is_alpha_utf(byte: &u8) -> bool {
-    CAPITAL.contains(byte) || LOWERCASE.contains(byte)
+/// Check if the given character is alphabetic, with no restriction to
+/// ASCII - accented and non-Latin letters count too.
+fn is_alpha_utf(ch: &char) -> bool {
+    ch.is_alphabetic()
 }
 
 /// const fn(ch: char) -> char
 ///
-/// Ensure the given character is lowercase.
+/// Ensure the given character is lowercase. Fails if `ch` isn't
+/// alphabetic, since a guess only makes sense for a letter.
 fn lowercase(ch: char) -> Result<char, GuessError> {
-    let chu8 = ch as u8;
-
-    if LOWERCASE.contains(&chu8) {
-        Ok(ch)
-    } else if CAPITAL.contains(&chu8) {
-        Ok( (chu8 + 32) as char )
+    if ch.is_alphabetic() {
+        Ok(ch.to_lowercase().next().unwrap_or(ch))
     } else {
         Err(GuessError)
     }
 }
 
 
-/// const fn() -> Result<String, Error>
+/// const fn() -> Result<(), Error>
 ///
-/// Verify that all the characters in the secret phrase are
-/// UTF-8 characters whose decimal representation is <128.
-fn validate_phrase(phrase: &str) {
-    let it = phrase.chars().zip(phrase.as_bytes().iter());
-
-    for (i, (c, b)) in it.enumerate() {
-        if *b >= 128 {
-            panic!("Character `{}` at index `{}` is not valid", c, i);
+/// Verify that the secret phrase is made up of only letters and
+/// whitespace. Unlike an earlier version of this function, this no
+/// longer restricts phrases to ASCII - `café` and `naïve` are fine.
+fn validate_phrase(phrase: &str) -> Result<(), Error> {
+    for (i, c) in phrase.chars().enumerate() {
+        if !c.is_alphabetic() && !c.is_whitespace() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("character `{}` at index `{}` is not valid", c, i),
+            ));
         }
     }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut game = HangmanGame::new("caf\u{e9} nap").unwrap();
+        game.update_guesses('c');
+        game.update_guesses('z');
+
+        let path = env::temp_dir().join(format!("hangman_save_test_{}.txt", std::process::id()));
+        game.save(&path).unwrap();
+
+        let loaded = HangmanGame::load(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.phrase, game.phrase);
+        assert_eq!(loaded.lives, game.lives);
+        assert_eq!(loaded.guesses, game.guesses);
+        assert_eq!(loaded.should_quit, game.should_quit);
+    }
+
+    #[test]
+    fn load_rejects_a_corrupted_phrase_line() {
+        let path = env::temp_dir().join(format!("hangman_corrupt_test_{}.txt", std::process::id()));
+        fs::write(&path, "not valid 123\n6\n\nfalse\n").unwrap();
+
+        let result = HangmanGame::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn correct_whole_word_guess_reveals_everything_and_ends_the_game() {
+        let mut game = HangmanGame::new("cat").unwrap();
+
+        game.evaluate_word_guess("CAT");
+
+        assert_eq!(game.wrong_guesses(), Vec::<char>::new());
+        assert!("cat".chars().all(|c| game.guesses.contains(&c)));
+        assert!(game.should_quit);
+        assert_eq!(game.lives, 6);
+    }
+
+    #[test]
+    fn incorrect_whole_word_guess_costs_the_word_guess_penalty() {
+        let mut game = HangmanGame::new("cat").unwrap();
+
+        game.evaluate_word_guess("dog");
+
+        assert_eq!(game.lives, 6 - WORD_GUESS_PENALTY);
+        assert!(!game.should_quit);
+    }
+
+    #[test]
+    fn a_penalty_that_empties_remaining_lives_ends_the_game() {
+        let mut game = HangmanGame::new("cat").unwrap();
+        game.lives = 1;
+
+        game.evaluate_word_guess("dog");
+
+        assert_eq!(game.lives, 0);
+        assert!(game.should_quit);
+    }
 }
@@ -3,7 +3,12 @@
 // `gallows.rs` but I don't have that strong of a sense of gallows humor
 
 use crossterm::cursor::MoveTo;
-use crossterm::style::Print;
+use crossterm::style::{
+    Color,
+    Print,
+    ResetColor,
+    SetForegroundColor,
+};
 use crossterm::terminal::{
     Clear, ClearType,
 };
@@ -78,6 +83,14 @@ impl GameBoard {
         Ok(())
     }
 
+    pub fn print_styled(string: &str, color: Color) -> Result<(), Error> {
+        Self::queue_command(SetForegroundColor(color))?;
+        Self::print(string)?;
+        Self::queue_command(ResetColor)?;
+
+        Ok(())
+    }
+
     // region: Gallows
 
     pub fn print_gallows(&self) -> Result<(), Error> {
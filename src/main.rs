@@ -1,14 +1,109 @@
 use std::env;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
 
+mod bench;
 mod game;
+mod solver;
+mod wordlist;
+
 use game::HangmanGame;
 
+// look for `--flag value` in `args`, returning the value if present
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
+
+// strip `--flag value` pairs out of `args`, leaving the rest untouched;
+// used to keep option flags out of a phrase built from the remaining args
+fn without_flags(args: &[String], flags: &[&str]) -> Vec<String> {
+    let mut kept = Vec::new();
+    let mut i = 0;
+
+    while i < args.len() {
+        if flags.contains(&args[i].as_str()) {
+            i += 2;
+        } else {
+            kept.push(args[i].clone());
+            i += 1;
+        }
+    }
+
+    kept
+}
+
+// the solver's candidate pool is only as good as the dictionary it's given;
+// the bundled wordlist is a few dozen words, nowhere near enough to solve
+// an arbitrary user-supplied secret, so `--solve` accepts `--dict <path>`
+// to point it at a real one (one word per line)
+fn load_dict(path: &str) -> Result<Vec<String>, Error> {
+    let contents = fs::read_to_string(path)?;
+
+    Ok(contents.lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+fn random_phrase(args: &[String]) -> Result<String, Error> {
+    let length = flag_value(args, "--length").and_then(|n| n.parse().ok());
+    let category = flag_value(args, "--category");
+
+    wordlist::random_word(length, category.as_deref()).ok_or_else(|| Error::new(
+        ErrorKind::NotFound,
+        "built-in wordlist has no entry matching the given --length/--category filters",
+    ))
+}
+
 fn play() -> Result<(), std::io::Error> {
     let args: Vec<String> = env::args().collect();
+    let save_path = flag_value(&args, "--save").map(PathBuf::from);
+
+    if args.get(1).map(String::as_str) == Some("--load") {
+        let path = flag_value(&args, "--load")
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "--load requires a path"))?;
+        let mut game = HangmanGame::load(Path::new(&path))?;
+
+        game.play()?;
+    } else if args.get(1).map(String::as_str) == Some("--solve") {
+        let dict_path = flag_value(&args, "--dict");
+        let dict = match &dict_path {
+            Some(path) => load_dict(path)?,
+            // without a real dictionary, the solver can only ever find
+            // secrets that happen to be in the small bundled wordlist
+            None => wordlist::words(),
+        };
+
+        let phrase = without_flags(args.get(2..).unwrap_or_default(), &["--dict"]).join(" ");
+        let mut game = HangmanGame::new(&phrase)?;
+
+        game.auto_play(&dict)?;
+    } else if args.get(1).map(String::as_str) == Some("--bench") {
+        let n: usize = args.get(2)
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(100);
 
-    if let Some(phrase_parts) = args.get(1..args.len()) {
+        bench::run(n, &wordlist::words()).print();
+    } else if args.get(1).map(String::as_str) == Some("--random") || args.len() <= 1 {
+        let phrase = random_phrase(&args)?;
+        let mut game = HangmanGame::new(&phrase)?;
+        if let Some(path) = save_path {
+            game = game.with_save_path(path);
+        }
+
+        game.play()?;
+    } else {
+        let phrase_parts = without_flags(&args[1..], &["--save"]);
         let phrase = phrase_parts.join(" ");
-        let mut game = HangmanGame::new(&phrase);
+        let mut game = HangmanGame::new(&phrase)?;
+        if let Some(path) = save_path {
+            game = game.with_save_path(path);
+        }
 
         game.play()?;
     }
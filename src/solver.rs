@@ -0,0 +1,120 @@
+// A small greedy solver: given the current hidden pattern, the letters
+// already guessed, and the letters known to be wrong, pick the next letter
+// most likely to reveal new information. This is intentionally simple -
+// no backtracking, no weighting by word likelihood, just candidate
+// filtering plus a frequency tally.
+//
+// The solver is only as good as `dict`: if the secret isn't drawn from (or
+// at least isn't similar to) the words in `dict`, candidate filtering will
+// quickly empty out and `next_guess` will return `None` well before the
+// phrase is solved. Callers driving a user-supplied secret should pass a
+// real dictionary, not a small curated wordlist.
+
+use std::collections::{HashMap, HashSet};
+
+// overall English letter frequency (most common first), used to break
+// ties when two candidate letters appear in the same number of words
+const LETTER_FREQUENCY: &str = "etaoinshrdlcumwfgypbvkjxqz";
+
+/// Choose the next letter to guess against the remaining candidate words.
+///
+/// `pattern` is the hidden phrase as rendered by `construct_hidden_phrase`
+/// (e.g. `"_a__"`), `guessed` is every letter tried so far, `wrong` is the
+/// subset of `guessed` that isn't in the secret phrase, and `dict` is the
+/// pool of candidate words to solve against. Returns `None` once no letter
+/// remains to try, either because every candidate word has been ruled out
+/// or every letter has already been guessed.
+pub fn next_guess(pattern: &str, guessed: &[char], wrong: &[char], dict: &[String]) -> Option<char> {
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    let candidates = dict.iter()
+        .filter(|word| matches_pattern(word, &pattern, guessed, wrong));
+
+    let mut tally: HashMap<char, usize> = HashMap::new();
+    for word in candidates {
+        let letters: HashSet<char> = word.chars().collect();
+        for letter in letters {
+            if !guessed.contains(&letter) {
+                *tally.entry(letter).or_insert(0) += 1;
+            }
+        }
+    }
+
+    tally.into_iter()
+        .max_by(|(c1, n1), (c2, n2)| n1.cmp(n2).then_with(|| frequency_rank(*c2).cmp(&frequency_rank(*c1))))
+        .map(|(letter, _)| letter)
+}
+
+// a candidate word matches the pattern if it's the right length, every
+// revealed position agrees, and no unrevealed position holds a letter
+// we've already guessed (if it did, it would have been revealed)
+fn matches_pattern(word: &str, pattern: &[char], guessed: &[char], wrong: &[char]) -> bool {
+    if word.chars().count() != pattern.len() {
+        return false;
+    }
+
+    for (letter, slot) in word.chars().zip(pattern.iter()) {
+        if *slot == '_' {
+            if wrong.contains(&letter) || guessed.contains(&letter) {
+                return false;
+            }
+        } else if letter != *slot {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn frequency_rank(letter: char) -> usize {
+    LETTER_FREQUENCY.find(letter).unwrap_or(LETTER_FREQUENCY.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn guesses_the_most_common_letter_among_matching_candidates() {
+        let dict = words(&["cat", "bat", "hat"]);
+        // every candidate matches `_a_`; `t` appears in all three, the
+        // other letters only once each, so `t` should win outright
+        assert_eq!(next_guess("_a_", &['a'], &[], &dict), Some('t'));
+    }
+
+    #[test]
+    fn excludes_words_that_would_repeat_a_guessed_letter_in_an_unrevealed_slot() {
+        let dict = words(&["cat", "cot"]);
+        // `cat` would put an already-guessed `a` in the unrevealed middle
+        // slot, which is impossible (it would already be revealed), so
+        // only `cot` survives filtering - if `cat` leaked through, its
+        // extra `c` would make `c` the tally winner instead of `o`
+        assert_eq!(next_guess("c_t", &['a', 't'], &[], &dict), Some('o'));
+    }
+
+    #[test]
+    fn tallies_across_every_word_free_of_the_known_wrong_letters() {
+        let dict = words(&["cat", "cop"]);
+        // `z` is known wrong, but neither word contains it, so both
+        // should match and `c` (common to both) should win the tally
+        assert_eq!(next_guess("c__", &[], &['z'], &dict), Some('c'));
+    }
+
+    #[test]
+    fn breaks_ties_with_overall_letter_frequency() {
+        let dict = words(&["ae", "az"]);
+        // `e` and `z` are tied at one candidate word each; `e` is far
+        // more common in English, so it should be preferred
+        assert_eq!(next_guess("a_", &['a'], &[], &dict), Some('e'));
+    }
+
+    #[test]
+    fn returns_none_once_no_candidates_remain() {
+        let dict = words(&["cat", "bat"]);
+        assert_eq!(next_guess("xyz", &[], &[], &dict), None);
+    }
+}